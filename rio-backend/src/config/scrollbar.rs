@@ -17,6 +17,16 @@ fn default_border_radius() -> f32 {
     4.0
 }
 
+/// Default fade-out duration for `Fading` mode, in milliseconds
+fn default_fade_duration_ms() -> u64 {
+    800
+}
+
+/// Default starting opacity for `Fading` mode
+fn default_opacity() -> f32 {
+    1.0
+}
+
 /// Default track color (semi-transparent dark)
 fn default_track_color() -> ColorArray {
     [0.25, 0.25, 0.25, 0.25]
@@ -43,6 +53,8 @@ pub enum ScrollbarMode {
     Auto,
     /// Never show the scrollbar
     Never,
+    /// Appear on scroll activity and fade out after a period of inactivity
+    Fading,
 }
 
 /// Scrollbar configuration
@@ -87,6 +99,14 @@ pub struct Scrollbar {
     /// Border radius for scrollbar elements
     #[serde(default = "default_border_radius", rename = "border-radius")]
     pub border_radius: f32,
+
+    /// Duration of the fade-out animation in `Fading` mode, in milliseconds
+    #[serde(default = "default_fade_duration_ms", rename = "fade-duration-ms")]
+    pub fade_duration_ms: u64,
+
+    /// Starting opacity the scrollbar fades out from in `Fading` mode
+    #[serde(default = "default_opacity")]
+    pub opacity: f32,
 }
 
 impl Default for Scrollbar {
@@ -99,6 +119,8 @@ impl Default for Scrollbar {
             thumb_hover_color: default_thumb_hover_color(),
             thumb_min_height: default_thumb_min_height(),
             border_radius: default_border_radius(),
+            fade_duration_ms: default_fade_duration_ms(),
+            opacity: default_opacity(),
         }
     }
 }
@@ -114,6 +136,8 @@ mod tests {
         assert_eq!(scrollbar.width, 8.0);
         assert_eq!(scrollbar.thumb_min_height, 20.0);
         assert_eq!(scrollbar.border_radius, 4.0);
+        assert_eq!(scrollbar.fade_duration_ms, 800);
+        assert_eq!(scrollbar.opacity, 1.0);
     }
 
     #[test]
@@ -135,5 +159,11 @@ mod tests {
         "#;
         let scrollbar: Scrollbar = toml::from_str(toml_str).unwrap();
         assert_eq!(scrollbar.mode, ScrollbarMode::Auto);
+
+        let toml_str = r#"
+            mode = "fading"
+        "#;
+        let scrollbar: Scrollbar = toml::from_str(toml_str).unwrap();
+        assert_eq!(scrollbar.mode, ScrollbarMode::Fading);
     }
 }